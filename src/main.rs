@@ -1,24 +1,256 @@
+use std::collections::hash_map::RandomState;
+use std::fs;
+use std::hash::{BuildHasher, Hasher};
 use std::io;
 use std::io::Write;
+use std::str::FromStr;
 
-fn main() {
+const PEOPLE_FILE: &str = "people.txt";
+
+struct Person {
+    name: String,
+    email: String,
+    age: u8,
+}
+
+impl Person {
+    fn new() -> Result<Person, String> {
+        let name = input("Name: ").map_err(|e| e.to_string())?;
+        if name.is_empty() {
+            return Err("name must not be empty".to_string());
+        }
+        if name.contains(',') {
+            return Err("name must not contain a comma".to_string());
+        }
+
+        let email = input("Email: ").map_err(|e| e.to_string())?;
+        if !email.contains('@') {
+            return Err("email must contain '@'".to_string());
+        }
+        if email.contains(',') {
+            return Err("email must not contain a comma".to_string());
+        }
+
+        let age: u8 = read_number("Age: ").map_err(|e| e.to_string())?;
+        if !(13..=140).contains(&age) {
+            return Err("age must be between 13 and 140".to_string());
+        }
+
+        Ok(Person { name, email, age })
+    }
+
+    fn to_line(&self) -> String {
+        format!("{},{},{}", self.name, self.email, self.age)
+    }
+}
+
+fn age_to_days(age: u16) -> u32 {
+    age as u32 * 365
+}
+
+fn input(prompt: &str) -> io::Result<String> {
+    print!("{}", prompt);
+    io::stdout().flush()?;
+    let mut line = String::new();
+    io::stdin().read_line(&mut line)?;
+    Ok(line.trim().to_string())
+}
+
+fn read_number<T: FromStr>(prompt: &str) -> Result<T, String> {
     loop {
-        print!("Input your age: ");
-        io::stdout().flush().unwrap();
-        let mut age = String::new();
-        io::stdin().read_line(&mut age).expect("No");
-        let age: i8 = match age.trim().parse() {
-                Ok(num) => num,
-                Err(_) => {
-                    println!("You absolute failure");
-                    break;
+        let line = input(prompt).map_err(|e| e.to_string())?;
+        match line.parse() {
+            Ok(num) => return Ok(num),
+            Err(_) => println!("That's not a valid number, try again."),
+        }
+    }
+}
+
+fn write_people(people: &[Person]) -> io::Result<()> {
+    let contents = people.iter().map(Person::to_line).collect::<Vec<_>>().join("\n");
+    fs::write(PEOPLE_FILE, contents)
+}
+
+fn read_people() -> io::Result<Vec<Person>> {
+    let contents = match fs::read_to_string(PEOPLE_FILE) {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(e),
+    };
+
+    let people = contents
+        .lines()
+        .filter_map(|line| {
+            let mut parts = line.splitn(3, ',');
+            let name = parts.next()?.to_string();
+            let email = parts.next()?.to_string();
+            let age: u8 = parts.next()?.parse().ok()?;
+            Some(Person { name, email, age })
+        })
+        .collect();
+
+    Ok(people)
+}
+
+fn do_math(a: f64, b: f64, op: char) -> Result<f64, String> {
+    match op {
+        '+' => Ok(a + b),
+        '-' => Ok(a - b),
+        '*' => Ok(a * b),
+        '/' => {
+            if b == 0.0 {
+                Err("cannot divide by zero".to_string())
+            } else {
+                Ok(a / b)
+            }
+        }
+        _ => Err(format!("unknown operator '{}'", op)),
+    }
+}
+
+fn roll_die() -> u8 {
+    let hasher = RandomState::new().build_hasher();
+    (hasher.finish() % 6) as u8 + 1
+}
+
+enum GameState {
+    ComeOut,
+    Rolling,
+    GameOver,
+}
+
+struct Game {
+    wallet: usize,
+    bet: usize,
+    state: GameState,
+}
+
+impl Game {
+    fn new(wallet: usize) -> Game {
+        Game { wallet, bet: 0, state: GameState::ComeOut }
+    }
+
+    fn read_bet(&self) -> usize {
+        loop {
+            let bet: usize = read_number("Place your bet: ").unwrap_or(0);
+            if bet > 0 && bet <= self.wallet {
+                return bet;
+            }
+            println!("Bet must be between 1 and your wallet ({})", self.wallet);
+        }
+    }
+
+    fn tick(&mut self) -> bool {
+        match self.state {
+            GameState::ComeOut => {
+                println!("Wallet: {}", self.wallet);
+                self.bet = self.read_bet();
+                self.state = GameState::Rolling;
+                true
+            }
+            GameState::Rolling => {
+                let die1 = roll_die();
+                let die2 = roll_die();
+                let total = die1 + die2;
+                println!("You rolled {} and {} (total {})", die1, die2, total);
+
+                if total == 7 || total == 11 {
+                    println!("You win!");
+                    self.wallet += self.bet;
+                } else {
+                    println!("You lose!");
+                    self.wallet -= self.bet;
                 }
-        };
-        if age < 18 {
-            println!("child");
-            break;
+
+                self.state = if self.wallet == 0 { GameState::GameOver } else { GameState::ComeOut };
+                true
+            }
+            GameState::GameOver => {
+                println!("Your wallet is empty, game over.");
+                false
+            }
+        }
+    }
+}
+
+fn run_dice_game() {
+    let wallet = loop {
+        let wallet: usize = read_number("Starting wallet: ").unwrap_or(0);
+        if wallet > 0 {
+            break wallet;
+        }
+        println!("Starting wallet must be greater than 0");
+    };
+    let mut game = Game::new(wallet);
+    while game.tick() {}
+}
+
+fn run_age_check() {
+    let mut people = read_people().expect("failed to read people.txt");
+
+    let person = match Person::new() {
+        Ok(person) => person,
+        Err(e) => {
+            println!("Could not register person: {}", e);
+            return;
+        }
+    };
+
+    if person.age < 18 {
+        println!("{} is a child", person.name);
+    } else {
+        println!("{} is an adult", person.name);
+    }
+
+    let days = age_to_days(person.age as u16);
+    let hours = days as u64 * 24;
+    let minutes = hours * 60;
+    println!("{} has lived ~{} days (~{} hours, ~{} minutes)", person.name, days, hours, minutes);
+
+    people.push(person);
+    write_people(&people).expect("failed to write people.txt");
+}
+
+fn run_calculator() {
+    let a: f64 = match read_number("First number: ") {
+        Ok(num) => num,
+        Err(e) => {
+            println!("Could not read number: {}", e);
+            return;
+        }
+    };
+    let b: f64 = match read_number("Second number: ") {
+        Ok(num) => num,
+        Err(e) => {
+            println!("Could not read number: {}", e);
+            return;
+        }
+    };
+    let op = match input("Operator (+, -, *, /): ") {
+        Ok(line) => line.chars().next().unwrap_or('\0'),
+        Err(e) => {
+            println!("Could not read operator: {}", e);
+            return;
+        }
+    };
+
+    match do_math(a, b, op) {
+        Ok(result) => println!("{} {} {} = {}", a, op, b, result),
+        Err(e) => println!("Could not compute result: {}", e),
+    }
+}
+
+fn main() {
+    loop {
+        let choice = input("Choose a mode (1: age check, 2: calculator, 3: dice game, 4: quit): ")
+            .expect("failed to read stdin");
+
+        match choice.as_str() {
+            "1" => run_age_check(),
+            "2" => run_calculator(),
+            "3" => run_dice_game(),
+            "4" => break,
+            _ => println!("Unknown option: {}", choice),
         }
-        println!("adult");
-        break; 
     }
-}
\ No newline at end of file
+}